@@ -2,20 +2,32 @@ use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::{io};
 use std::sync::{Arc};
-use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::atomic::{AtomicU8, AtomicUsize, Ordering};
+use std::sync::mpsc as std_mpsc;
+use std::time::UNIX_EPOCH;
 use futures::{Stream};
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use jwalk::WalkDir as JWalkDir;
 use rayon::iter::IntoParallelRefIterator;
+use rayon::iter::ParallelBridge;
 use serde::{Deserialize, Serialize};
 use tokio::sync::mpsc;
+use tokio::sync::Notify;
 use walkdir::WalkDir;
 use tokio_stream::wrappers::UnboundedReceiverStream;
 use rayon::iter::ParallelIterator;
 
 /// Enum representing possible errors during the hashing process.
+///
+/// Both variants carry the offending path (when known) and the underlying
+/// error message (the OS error string for I/O failures, the pattern error
+/// for a malformed filter glob), so a single failing file can be reported and skipped
+/// without aborting the rest of the batch.
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub enum ProgressHashingError {
-    ErrHashingFile,
-    ErrCollectingFiles,
+    ErrHashingFile { path: PathBuf, message: String },
+    ErrCollectingFiles { path: Option<PathBuf>, message: String },
 }
 
 /// Struct representing the current file update status.
@@ -28,10 +40,168 @@ pub struct CurrentFileUpdate {
 /// Enum representing the work status of the hashing process.
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub enum WorkStatus {
+    /// The total number of files to process, known up front. Emitted only by
+    /// [`verify_against`], which enumerates its file list before starting;
+    /// [`progressed_hashing`] discovers files incrementally and reports
+    /// [`WorkStatus::Discovered`] instead.
     Started(usize),
     Progress(CurrentFileUpdate),
-    Result(HashMap<PathBuf, String>),
+    /// The final per-file outcome map: `Ok` for a successfully hashed file,
+    /// `Err` for one that failed without aborting the rest of the batch.
+    Result(HashMap<PathBuf, Result<String, ProgressHashingError>>),
     Error(ProgressHashingError),
+    /// A single digest representing the entire directory, independent of
+    /// filesystem enumeration order.
+    RootHash(String),
+    /// A file whose hash was reused from the manifest because its size and
+    /// modification time hadn't changed since the last run.
+    Skipped(PathBuf),
+    /// The verdict for a single path when verifying a directory against an
+    /// expected manifest.
+    Verdict { path: PathBuf, state: VerificationState },
+    /// The job was cancelled via its `JobControl` before all files were hashed.
+    Cancelled,
+    /// A running count of files discovered by the directory walk so far.
+    /// Emitted incrementally while the walk is still in progress, since the
+    /// total file count isn't known up front. [`progressed_hashing`] emits
+    /// this in place of [`WorkStatus::Started`] — consumers streaming its
+    /// output need to switch from matching `Started` to tracking the latest
+    /// `Discovered` count, since this is a breaking change to the sequence
+    /// it produces.
+    Discovered(usize),
+    /// A Bao outboard encoding for one file, emitted when
+    /// `HashingOptions::generate_outboard` is set. `root` is the file's
+    /// BLAKE3 hash as recomputed by the outboard tree itself — it always
+    /// matches the corresponding entry in `WorkStatus::Result`, so a verifier
+    /// can confirm `bytes` against a root hash obtained independently.
+    Outboard { path: PathBuf, root: String, bytes: Vec<u8> },
+}
+
+const JOB_RUNNING: u8 = 0;
+const JOB_PAUSED: u8 = 1;
+const JOB_CANCELLED: u8 = 2;
+
+/// A handle for pausing, resuming, or cancelling an in-flight hashing job.
+///
+/// Cloning a `JobControl` shares the same underlying job; any clone can
+/// pause, resume, or cancel it.
+#[derive(Clone)]
+pub struct JobControl {
+    state: Arc<AtomicU8>,
+    notify: Arc<Notify>,
+}
+
+impl JobControl {
+    fn new() -> Self {
+        Self {
+            state: Arc::new(AtomicU8::new(JOB_RUNNING)),
+            notify: Arc::new(Notify::new()),
+        }
+    }
+
+    /// Pauses the job. Files already being hashed run to completion; no new
+    /// file starts hashing until [`JobControl::resume`] is called.
+    pub fn pause(&self) {
+        self.state.store(JOB_PAUSED, Ordering::SeqCst);
+    }
+
+    /// Resumes a paused job.
+    pub fn resume(&self) {
+        self.state.store(JOB_RUNNING, Ordering::SeqCst);
+        self.notify.notify_waiters();
+    }
+
+    /// Cancels the job. Files not yet started are skipped and the stream
+    /// emits a single `WorkStatus::Cancelled` in place of its final result.
+    pub fn cancel(&self) {
+        self.state.store(JOB_CANCELLED, Ordering::SeqCst);
+        self.notify.notify_waiters();
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.state.load(Ordering::SeqCst) == JOB_CANCELLED
+    }
+
+    /// Blocks the calling (blocking) thread until the job leaves the paused
+    /// state, parking on `notify` instead of polling so `resume`/`cancel`
+    /// wake it immediately.
+    ///
+    /// `resume`/`cancel` call `notify_waiters`, which (unlike `notify_one`)
+    /// stores no permit for a waiter that hasn't registered yet. So the
+    /// `Notified` future must be registered via `enable()` *before* the state
+    /// is re-checked: if it were created and polled only after that check,
+    /// a `notify_waiters()` landing in between would be missed entirely and
+    /// the park below would never wake.
+    fn wait_while_paused(&self) {
+        loop {
+            if self.state.load(Ordering::SeqCst) != JOB_PAUSED {
+                return;
+            }
+
+            let notified = self.notify.notified();
+            tokio::pin!(notified);
+            notified.as_mut().enable();
+
+            if self.state.load(Ordering::SeqCst) != JOB_PAUSED {
+                return;
+            }
+
+            futures::executor::block_on(notified);
+        }
+    }
+}
+
+/// The outcome of comparing a hashed file against an expected manifest entry.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub enum VerificationState {
+    /// The file's hash matches the expected manifest entry.
+    Unchanged,
+    /// The file exists in both places but its hash differs.
+    Modified,
+    /// The file was found on disk but isn't in the expected manifest.
+    Added,
+    /// The file is in the expected manifest but is missing on disk.
+    Missing,
+}
+
+/// A manifest entry recording enough metadata about a previously hashed file
+/// to detect whether it has changed without re-reading its contents.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ManifestEntry {
+    pub hash: String,
+    pub len: u64,
+    pub mtime_nanos: u128,
+}
+
+/// A persisted record of per-file hashes from a previous run, keyed by the
+/// path that was hashed.
+pub type Manifest = HashMap<PathBuf, ManifestEntry>;
+
+/// Loads a manifest from disk, returning an empty manifest if the file
+/// doesn't exist yet or can't be parsed.
+fn load_manifest(manifest_path: &Path) -> Manifest {
+    std::fs::read(manifest_path)
+        .ok()
+        .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+        .unwrap_or_default()
+}
+
+/// Persists a manifest to disk as JSON.
+fn save_manifest(manifest_path: &Path, manifest: &Manifest) -> io::Result<()> {
+    let bytes = serde_json::to_vec(manifest).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+    std::fs::write(manifest_path, bytes)
+}
+
+/// Reads the length and modification time (as nanoseconds since the Unix
+/// epoch) of the file at `path`.
+fn stat_len_and_mtime(path: &Path) -> io::Result<(u64, u128)> {
+    let metadata = std::fs::metadata(path)?;
+    let mtime_nanos = metadata
+        .modified()?
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos();
+    Ok((metadata.len(), mtime_nanos))
 }
 
 /// Collects all files in the given directory.
@@ -54,6 +224,158 @@ fn collect_files_in_dir(dir: &Path) -> Result<Vec<PathBuf>, walkdir::Error> {
     Ok(files)
 }
 
+/// Options controlling which files get hashed.
+///
+/// Defaults to hashing every file in the tree, matching the crate's
+/// historical behavior.
+#[derive(Clone, Debug, Default)]
+pub struct HashingOptions {
+    /// Glob patterns a file's path (relative to the hashed directory) must
+    /// match to be hashed. An empty list matches every file.
+    pub include: Vec<String>,
+    /// Glob patterns that exclude a file, or prune a directory before it's
+    /// descended into.
+    pub exclude: Vec<String>,
+    /// Honor the `.gitignore` file at the root of the hashed directory.
+    /// `.gitignore` files in subdirectories are not read.
+    pub respect_gitignore: bool,
+    /// Additionally emit a Bao-style outboard chunk tree for each file that is
+    /// hashed, so a verifier can later confirm an arbitrary byte range against
+    /// the root hash without re-reading the whole file. Files whose hash is
+    /// reused from the manifest are not re-read and get no outboard.
+    pub generate_outboard: bool,
+}
+
+fn build_glob_set(patterns: &[String]) -> Result<Option<GlobSet>, globset::Error> {
+    if patterns.is_empty() {
+        return Ok(None);
+    }
+
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        builder.add(Glob::new(pattern)?);
+    }
+    builder.build().map(Some)
+}
+
+/// The compiled form of a `HashingOptions`, ready to test paths against
+/// without re-parsing glob patterns per entry.
+struct CompiledFilter {
+    include: Option<GlobSet>,
+    exclude: Option<GlobSet>,
+    gitignore: Option<Gitignore>,
+}
+
+impl CompiledFilter {
+    /// Compiles `options` into a filter, or the `globset::Error` from the
+    /// first malformed include/exclude pattern. A typo'd pattern is a
+    /// correctness bug for callers relying on it to prune or restrict the
+    /// hashed set, so it's surfaced rather than silently ignored.
+    fn build(base: &Path, options: &HashingOptions) -> Result<Self, globset::Error> {
+        let gitignore = if options.respect_gitignore {
+            let mut builder = GitignoreBuilder::new(base);
+            builder.add(base.join(".gitignore"));
+            builder.build().ok()
+        } else {
+            None
+        };
+
+        Ok(Self {
+            include: build_glob_set(&options.include)?,
+            exclude: build_glob_set(&options.exclude)?,
+            gitignore,
+        })
+    }
+
+    /// Whether `path` should be hashed (if a file) or descended into (if a
+    /// directory).
+    fn allows(&self, base: &Path, path: &Path, is_dir: bool) -> bool {
+        if let Some(gitignore) = &self.gitignore {
+            if gitignore.matched(path, is_dir).is_ignore() {
+                return false;
+            }
+        }
+
+        let rel_path = relative_path_string(base, path);
+
+        if let Some(exclude) = &self.exclude {
+            if exclude.is_match(&rel_path) {
+                return false;
+            }
+        }
+
+        if !is_dir {
+            if let Some(include) = &self.include {
+                if !include.is_match(&rel_path) {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+}
+
+/// Walks `dir` in a dedicated thread using `jwalk`'s parallel directory
+/// traversal, feeding each discovered file path into a bounded channel as
+/// soon as it's found, and emitting a `WorkStatus::Discovered` count on
+/// `status_tx` along the way.
+///
+/// This lets hashing start on the first few files while the walk is still
+/// descending into the rest of the tree, instead of waiting for the entire
+/// directory to be enumerated up front. `options` is applied as entries are
+/// read, so an excluded directory (e.g. `node_modules`) is pruned before the
+/// walk descends into it rather than being hashed and filtered afterward.
+fn spawn_file_walker(dir: PathBuf, status_tx: mpsc::UnboundedSender<WorkStatus>, options: HashingOptions) -> std_mpsc::Receiver<PathBuf> {
+    let (path_tx, path_rx) = std_mpsc::sync_channel::<PathBuf>(1024);
+
+    std::thread::spawn(move || {
+        let filter = match CompiledFilter::build(&dir, &options) {
+            Ok(filter) => filter,
+            Err(err) => {
+                let progress_err = ProgressHashingError::ErrCollectingFiles {
+                    path: None,
+                    message: err.to_string(),
+                };
+                let _ = status_tx.send(WorkStatus::Error(progress_err));
+                return;
+            }
+        };
+        let filter_root = dir.clone();
+        let mut discovered = 0usize;
+
+        let walker = JWalkDir::new(&dir).process_read_dir(move |_depth, _path, _read_dir_state, children| {
+            children.retain(|entry| {
+                entry.as_ref()
+                    .map(|entry| filter.allows(&filter_root, &entry.path(), entry.file_type().is_dir()))
+                    .unwrap_or(true)
+            });
+        });
+
+        for entry in walker {
+            match entry {
+                Ok(entry) if entry.file_type().is_file() => {
+                    discovered += 1;
+                    let _ = status_tx.send(WorkStatus::Discovered(discovered));
+                    if path_tx.send(entry.path()).is_err() {
+                        break;
+                    }
+                }
+                Ok(_) => {}
+                Err(err) => {
+                    let progress_err = ProgressHashingError::ErrCollectingFiles {
+                        path: err.path().map(|path| path.to_path_buf()),
+                        message: err.to_string(),
+                    };
+                    let _ = status_tx.send(WorkStatus::Error(progress_err));
+                }
+            }
+        }
+    });
+
+    path_rx
+}
+
 /// Calculates the BLAKE3 hash of the file at the given path.
 ///
 /// # Arguments
@@ -70,58 +392,335 @@ fn calculate_hash_with_blake3(path: &Path) -> io::Result<String> {
     Ok(hasher.finalize().to_hex().to_string())
 }
 
+/// Builds a Bao outboard encoding for the file at `path`, streaming its
+/// contents through [`bao::encode::Encoder`] rather than reading the whole
+/// file into memory.
+///
+/// The encoder's finalized hash is BLAKE3's real chunk/parent tree over the
+/// file's bytes, so it equals [`calculate_hash_with_blake3`]'s output for the
+/// same file. Returning it alongside the outboard bytes is what lets a
+/// verifier confirm an arbitrary byte range against a root hash obtained
+/// independently, by recomputing only the O(log n) nodes on the path to the
+/// root instead of re-reading the whole file.
+fn build_outboard(path: &Path) -> io::Result<(String, Vec<u8>)> {
+    let mut file = std::fs::File::open(path)?;
+    // `Encoder` seeks backward to patch in parent node headers once their
+    // subtrees are written, so its sink must be `Seek` as well as `Write` —
+    // a bare `Vec<u8>` isn't, hence the `Cursor` wrapper.
+    let mut encoder = bao::encode::Encoder::new_outboard(io::Cursor::new(Vec::new()));
+    io::copy(&mut file, &mut encoder)?;
+    let hash = encoder.finalize()?;
+    let outboard = encoder.into_inner().into_inner();
+    Ok((hash.to_hex().to_string(), outboard))
+}
+
+/// Computes the relative path of `full` against `base` as a forward-slash
+/// separated string, falling back to the full path if it isn't nested under
+/// `base`.
+fn relative_path_string(base: &Path, full: &Path) -> String {
+    full.strip_prefix(base)
+        .unwrap_or(full)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+/// Computes a single deterministic digest for an entire directory from its
+/// per-file hashes.
+///
+/// The file hashes are paired with their path relative to `base`, sorted
+/// lexicographically by that relative path, and fed into a fresh
+/// `blake3::Hasher` as length-prefixed `(path, hash)` entries. Sorting first
+/// is the critical invariant: it makes the resulting root hash independent
+/// of filesystem enumeration order, so two machines scanning the same
+/// content always agree on the same root.
+///
+/// # Arguments
+///
+/// * `base` - The root directory the hashed files were collected from.
+/// * `file_hashes` - The per-file outcomes produced by [`progressed_hashing`].
+///   Files that failed to hash are excluded from the digest.
+///
+/// # Returns
+///
+/// The finalized BLAKE3 hash of the directory, as a hex string.
+pub fn directory_root_hash(base: &Path, file_hashes: &HashMap<PathBuf, Result<String, ProgressHashingError>>) -> String {
+    let mut entries: Vec<(String, &str)> = file_hashes
+        .iter()
+        .filter_map(|(path, result)| result.as_ref().ok().map(|hash| (relative_path_string(base, path), hash.as_str())))
+        .collect();
+    entries.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let mut hasher = blake3::Hasher::new();
+    for (rel_path, hash) in entries {
+        let path_bytes = rel_path.as_bytes();
+        hasher.update(&(path_bytes.len() as u64).to_le_bytes());
+        hasher.update(path_bytes);
+
+        let hash_bytes = hash.as_bytes();
+        hasher.update(&(hash_bytes.len() as u64).to_le_bytes());
+        hasher.update(hash_bytes);
+    }
+    hasher.finalize().to_hex().to_string()
+}
+
+/// One file's outcome from the parallel hashing pass: its path, the hash (or
+/// error) to report, and the manifest entry to persist, if any.
+type FileHashOutcome = (PathBuf, Result<String, ProgressHashingError>, Option<ManifestEntry>);
+
 /// Asynchronously hashes files in the given directory, providing progress updates.
 ///
 /// # Arguments
 ///
 /// * `file_path` - A reference to the path of the directory to hash files from.
+/// * `manifest_path` - An optional path to a persisted manifest from a previous
+///   run. When given, files whose size and modification time still match the
+///   manifest are reused instead of re-read, and the manifest is rewritten
+///   with the up-to-date results once hashing completes.
+/// * `options` - Which files to include or exclude from hashing. Defaults to
+///   hashing everything under `file_path`.
 ///
 /// # Returns
 ///
-/// A stream of `WorkStatus` items representing the progress and result of the hashing process.
-pub async fn progressed_hashing(file_path: &Path) -> impl Stream<Item = WorkStatus> {
+/// A stream of `WorkStatus` items representing the progress and result of the
+/// hashing process, paired with a `JobControl` that can pause, resume, or
+/// cancel it mid-flight.
+///
+/// Unlike [`verify_against`], this stream never emits `WorkStatus::Started`:
+/// since files are discovered incrementally by the directory walk, the total
+/// count isn't known up front, so progress is reported via running
+/// `WorkStatus::Discovered` counts instead. Consumers written against the
+/// older `Started`-based protocol must switch to tracking the latest
+/// `Discovered` count, which is already the running total.
+pub async fn progressed_hashing(file_path: &Path, manifest_path: Option<&Path>, options: HashingOptions) -> (impl Stream<Item = WorkStatus>, JobControl) {
+    let (tx, rx) = mpsc::unbounded_channel();
+    let control = JobControl::new();
+
+    let files_hashed_counter = Arc::new(AtomicUsize::new(0));
+    let base_dir = file_path.to_path_buf();
+    let manifest = manifest_path.map(load_manifest).unwrap_or_default();
+    let manifest_path = manifest_path.map(|path| path.to_path_buf());
+    let job_control = control.clone();
+    let generate_outboard = options.generate_outboard;
+    let path_rx = spawn_file_walker(base_dir.clone(), tx.clone(), options);
+
+    tokio::task::spawn_blocking(move || {
+        let files_hashed_counter = Arc::clone(&files_hashed_counter);
+
+        let file_results: Vec<Option<FileHashOutcome>> = path_rx.into_iter().par_bridge().map(|path_buf: PathBuf| {
+            let path_buf = &path_buf;
+            if job_control.is_cancelled() {
+                return None;
+            }
+            job_control.wait_while_paused();
+            if job_control.is_cancelled() {
+                return None;
+            }
+
+            if let Some(cached) = manifest.get(path_buf) {
+                if let Ok((len, mtime_nanos)) = stat_len_and_mtime(path_buf) {
+                    if len == cached.len && mtime_nanos == cached.mtime_nanos {
+                        if tx.send(WorkStatus::Skipped(path_buf.clone())).is_err() {
+                            return None;
+                        }
+                        return Some((path_buf.clone(), Ok(cached.hash.clone()), Some(ManifestEntry {
+                            hash: cached.hash.clone(),
+                            len,
+                            mtime_nanos,
+                        })));
+                    }
+                }
+            }
+
+            // With `generate_outboard` set, the outboard encoder's finalized
+            // hash is already the file's real BLAKE3 root, so it doubles as
+            // the file hash instead of a second full read via
+            // `calculate_hash_with_blake3`.
+            let hash_result = if generate_outboard {
+                build_outboard(path_buf).map(|(root, bytes)| (root, Some(bytes)))
+            } else {
+                calculate_hash_with_blake3(path_buf).map(|hash| (hash, None))
+            };
+
+            match hash_result {
+                Ok((hash, outboard_bytes)) => {
+                    let total_hashed_files = files_hashed_counter.fetch_add(1, Ordering::SeqCst);
+
+                    if tx.send(WorkStatus::Progress(CurrentFileUpdate {
+                        current_file: path_buf.display().to_string(),
+                        total_hashed_files,
+                    })).is_err() {
+                        return None;
+                    }
+
+                    if let Some(bytes) = outboard_bytes {
+                        let _ = tx.send(WorkStatus::Outboard { path: path_buf.clone(), root: hash.clone(), bytes });
+                    }
+
+                    let manifest_entry = stat_len_and_mtime(path_buf).ok().map(|(len, mtime_nanos)| ManifestEntry {
+                        hash: hash.clone(),
+                        len,
+                        mtime_nanos,
+                    });
+
+                    Some((path_buf.clone(), Ok(hash), manifest_entry))
+                },
+                Err(err) => {
+                    let progress_err = ProgressHashingError::ErrHashingFile {
+                        path: path_buf.clone(),
+                        message: err.to_string(),
+                    };
+                    let _ = tx.send(WorkStatus::Error(progress_err.clone()));
+                    Some((path_buf.clone(), Err(progress_err), None))
+                }
+            }
+
+        }).collect();
+
+        if job_control.is_cancelled() {
+            let _ = tx.send(WorkStatus::Cancelled);
+            return;
+        }
+
+        let mut file_hashes = HashMap::with_capacity(file_results.len());
+        let mut updated_manifest: Manifest = HashMap::with_capacity(file_results.len());
+        for (path, result, entry) in file_results.into_iter().flatten() {
+            if let Some(entry) = entry {
+                updated_manifest.insert(path.clone(), entry);
+            }
+            file_hashes.insert(path, result);
+        }
+
+        if let Some(manifest_path) = manifest_path.as_deref() {
+            if let Err(err) = save_manifest(manifest_path, &updated_manifest) {
+                eprintln!("Error persisting manifest: {:?}", err);
+            }
+        }
+
+        let root_hash = directory_root_hash(&base_dir, &file_hashes);
+
+        let _ = tx.send(WorkStatus::Result(file_hashes));
+        let _ = tx.send(WorkStatus::RootHash(root_hash));
+    });
+
+    (UnboundedReceiverStream::new(rx), control)
+}
+
+/// Walks `file_path` and streams a verdict for every file found, comparing
+/// it against `expected` instead of emitting raw hashes. A file that fails to
+/// hash is reported as `WorkStatus::Error` instead of a verdict.
+///
+/// Once the walk completes, entries present in `expected` but absent on
+/// disk are also streamed as `VerificationState::Missing`, so a caller can
+/// detect deleted files in addition to modified or newly added ones.
+///
+/// # Arguments
+///
+/// * `file_path` - A reference to the path of the directory to verify.
+/// * `expected` - The trusted reference mapping of paths to BLAKE3 hex hashes.
+///
+/// # Returns
+///
+/// A stream of `WorkStatus` items representing the progress and per-file verdicts.
+pub async fn verify_against(file_path: &Path, expected: HashMap<PathBuf, String>) -> impl Stream<Item = WorkStatus> {
     let (tx, rx) = mpsc::unbounded_channel();
 
     let file_paths = match collect_files_in_dir(file_path) {
         Ok(paths) => {
-            tx.send(WorkStatus::Started(paths.len())).unwrap();
+            let _ = tx.send(WorkStatus::Started(paths.len()));
             paths
         }
-        Err(_err) => {
-            eprintln!("Error collecting files: {:?}", _err);
-            tx.send(WorkStatus::Error(ProgressHashingError::ErrCollectingFiles)).unwrap();
+        Err(err) => {
+            let progress_err = ProgressHashingError::ErrCollectingFiles {
+                path: err.path().map(|path| path.to_path_buf()),
+                message: err.to_string(),
+            };
+            let _ = tx.send(WorkStatus::Error(progress_err));
             return UnboundedReceiverStream::new(rx);
         }
     };
 
     let files_hashed_counter = Arc::new(AtomicUsize::new(0));
+    let walked: std::collections::HashSet<PathBuf> = file_paths.iter().cloned().collect();
 
     tokio::task::spawn_blocking(move || {
         let files_hashed_counter = Arc::clone(&files_hashed_counter);
 
-        let file_hashes: HashMap<PathBuf, String> = file_paths.par_iter().map(|path_buf: &PathBuf| {
+        file_paths.par_iter().for_each(|path_buf: &PathBuf| {
             match calculate_hash_with_blake3(path_buf) {
                 Ok(hash) => {
                     let total_hashed_files = files_hashed_counter.fetch_add(1, Ordering::SeqCst);
 
-                    tx.send(WorkStatus::Progress(CurrentFileUpdate {
+                    if tx.send(WorkStatus::Progress(CurrentFileUpdate {
                         current_file: path_buf.display().to_string(),
                         total_hashed_files,
-                    })).unwrap();
+                    })).is_err() {
+                        return;
+                    }
+
+                    let state = match expected.get(path_buf) {
+                        Some(expected_hash) if *expected_hash == hash => VerificationState::Unchanged,
+                        Some(_) => VerificationState::Modified,
+                        None => VerificationState::Added,
+                    };
 
-                    (path_buf.clone(), hash)
+                    let _ = tx.send(WorkStatus::Verdict { path: path_buf.clone(), state });
                 },
-                Err(_err) => {
-                    eprintln!("Error hashing file: {:?}", _err);
-                    tx.send(WorkStatus::Error(ProgressHashingError::ErrHashingFile)).unwrap();
-                    (path_buf.clone(), "".to_string())
+                Err(err) => {
+                    let progress_err = ProgressHashingError::ErrHashingFile {
+                        path: path_buf.clone(),
+                        message: err.to_string(),
+                    };
+                    let _ = tx.send(WorkStatus::Error(progress_err));
                 }
             }
+        });
 
-        }).collect();
-
-        tx.send(WorkStatus::Result(file_hashes)).unwrap();
+        for path in expected.keys() {
+            if !walked.contains(path)
+                && tx.send(WorkStatus::Verdict {
+                    path: path.clone(),
+                    state: VerificationState::Missing,
+                }).is_err()
+            {
+                break;
+            }
+        }
     });
 
     UnboundedReceiverStream::new(rx)
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn directory_root_hash_is_order_independent() {
+        let base = Path::new("/tmp/progressed_hashing_example");
+
+        let mut shuffled_a: HashMap<PathBuf, Result<String, ProgressHashingError>> = HashMap::new();
+        shuffled_a.insert(base.join("b.txt"), Ok("hash-b".to_string()));
+        shuffled_a.insert(base.join("a.txt"), Ok("hash-a".to_string()));
+        shuffled_a.insert(base.join("sub/c.txt"), Ok("hash-c".to_string()));
+
+        let mut shuffled_b: HashMap<PathBuf, Result<String, ProgressHashingError>> = HashMap::new();
+        shuffled_b.insert(base.join("sub/c.txt"), Ok("hash-c".to_string()));
+        shuffled_b.insert(base.join("a.txt"), Ok("hash-a".to_string()));
+        shuffled_b.insert(base.join("b.txt"), Ok("hash-b".to_string()));
+
+        assert_eq!(directory_root_hash(base, &shuffled_a), directory_root_hash(base, &shuffled_b));
+    }
+
+    #[test]
+    fn outboard_root_matches_file_hash() {
+        let path = std::env::temp_dir().join(format!("progressed_hashing_test_outboard_{}", std::process::id()));
+        std::fs::write(&path, b"the quick brown fox jumps over the lazy dog".repeat(1000)).unwrap();
+
+        let file_hash = calculate_hash_with_blake3(&path).unwrap();
+        let (outboard_root, _bytes) = build_outboard(&path).unwrap();
+
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(file_hash, outboard_root);
+    }
+}